@@ -0,0 +1,163 @@
+//! How a run of children is spaced out along the primary axis.
+
+/// How a sequence of children is spaced out along the main axis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Distribution {
+    /// Pack all children at the start, free space goes to the end.
+    Start,
+    /// Pack all children at the end, free space goes to the start.
+    End,
+    /// Pack all children in the middle, free space is split evenly at both
+    /// ends.
+    Center,
+    /// Equal gaps between children, none before the first or after the
+    /// last.
+    Between,
+    /// Equal half-gaps before the first and after the last child, full gaps
+    /// in between.
+    Around,
+    /// Equal gaps everywhere, including before the first and after the last
+    /// child.
+    Evenly,
+}
+
+impl Distribution {
+    /// Partition the free space along an axis of length `extent` into the
+    /// gaps that separate `sizes.len()` children of the given `sizes`
+    /// (along that axis), returning `sizes.len() + 1` gap lengths, the first
+    /// preceding and the last following the children.
+    ///
+    /// A single child collapses `between`, `around` and `evenly` to
+    /// `center`, since there is no run to distribute. With no children at
+    /// all, the whole extent is returned as one gap. Negative free space
+    /// (the children overflow `extent`) falls back to `start` packing.
+    pub fn partition(self, extent: f64, sizes: &[f64]) -> Vec<f64> {
+        let n = sizes.len();
+        let free = extent - sizes.iter().sum::<f64>();
+
+        if n == 0 {
+            return vec![free];
+        }
+
+        if free < 0.0 {
+            let mut gaps = vec![0.0; n + 1];
+            gaps[n] = free;
+            return gaps;
+        }
+
+        let this = if n == 1 {
+            match self {
+                Self::Between | Self::Around | Self::Evenly => Self::Center,
+                other => other,
+            }
+        } else {
+            self
+        };
+
+        let mut gaps = vec![0.0; n + 1];
+        match this {
+            Self::Start => gaps[n] = free,
+            Self::End => gaps[0] = free,
+            Self::Center => {
+                gaps[0] = free / 2.0;
+                gaps[n] = free / 2.0;
+            }
+            Self::Between => {
+                let gap = free / (n - 1) as f64;
+                gaps[1 .. n].iter_mut().for_each(|slot| *slot = gap);
+            }
+            Self::Around => {
+                let gap = free / n as f64;
+                gaps[0] = gap / 2.0;
+                gaps[n] = gap / 2.0;
+                gaps[1 .. n].iter_mut().for_each(|slot| *slot = gap);
+            }
+            Self::Evenly => {
+                let gap = free / (n + 1) as f64;
+                gaps.iter_mut().for_each(|slot| *slot = gap);
+            }
+        }
+
+        gaps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_children_returns_single_gap() {
+        for mode in [
+            Distribution::Start,
+            Distribution::Center,
+            Distribution::Between,
+            Distribution::Around,
+            Distribution::Evenly,
+        ] {
+            assert_eq!(mode.partition(10.0, &[]), vec![10.0]);
+        }
+    }
+
+    #[test]
+    fn single_child_collapses_to_center() {
+        for mode in [Distribution::Between, Distribution::Around, Distribution::Evenly] {
+            assert_eq!(mode.partition(10.0, &[4.0]), vec![3.0, 3.0]);
+        }
+        assert_eq!(Distribution::Start.partition(10.0, &[4.0]), vec![0.0, 6.0]);
+        assert_eq!(Distribution::End.partition(10.0, &[4.0]), vec![6.0, 0.0]);
+    }
+
+    #[test]
+    fn overflow_falls_back_to_start_packing() {
+        for mode in [
+            Distribution::Start,
+            Distribution::End,
+            Distribution::Center,
+            Distribution::Between,
+            Distribution::Around,
+            Distribution::Evenly,
+        ] {
+            assert_eq!(mode.partition(5.0, &[4.0, 4.0]), vec![0.0, 0.0, -3.0]);
+        }
+    }
+
+    #[test]
+    fn start_packs_children_with_free_space_at_the_end() {
+        assert_eq!(Distribution::Start.partition(10.0, &[2.0, 2.0]), vec![0.0, 0.0, 6.0]);
+    }
+
+    #[test]
+    fn end_packs_children_with_free_space_at_the_start() {
+        assert_eq!(Distribution::End.partition(10.0, &[2.0, 2.0]), vec![6.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn center_splits_free_space_evenly_at_the_ends() {
+        assert_eq!(Distribution::Center.partition(10.0, &[2.0, 2.0]), vec![3.0, 0.0, 3.0]);
+    }
+
+    #[test]
+    fn between_puts_gaps_only_in_the_middle() {
+        assert_eq!(
+            Distribution::Between.partition(10.0, &[2.0, 2.0, 2.0]),
+            vec![0.0, 2.0, 2.0, 0.0]
+        );
+    }
+
+    #[test]
+    fn around_gives_half_gaps_at_the_ends() {
+        assert_eq!(
+            Distribution::Around.partition(12.0, &[2.0, 2.0, 2.0]),
+            vec![1.0, 2.0, 2.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn evenly_spaces_all_n_plus_one_gaps_equally() {
+        assert_eq!(
+            Distribution::Evenly.partition(10.0, &[2.0, 2.0, 2.0]),
+            vec![1.0, 1.0, 1.0, 1.0]
+        );
+    }
+}
@@ -0,0 +1,48 @@
+//! The layouting engine: turns a syntax tree annotated with styling commands
+//! into a positioned document.
+
+use crate::syntax::tree::SynTree;
+use primitive::{LayoutAlign, LayoutSystem};
+use distribute::Distribution;
+
+pub mod primitive;
+pub mod distribute;
+pub mod stack;
+
+/// Commonly used layouting types, re-exported for library functions.
+pub mod prelude {
+    pub use super::primitive::*;
+    pub use super::distribute::*;
+    pub use super::{Command, Commands, LayoutState};
+}
+
+/// A single instruction emitted by a library function, describing one change
+/// to make to the state the layouting engine lays content out with, or a
+/// subtree to lay out under the current state.
+#[derive(Debug, Clone)]
+pub enum Command {
+    /// Change the alignment used for subsequently laid out content.
+    SetAlignment(LayoutAlign),
+    /// Change the distribution mode used for runs of children laid out
+    /// along the primary axis.
+    SetDistribution(Distribution),
+    /// Layout the given syntax tree under the current state.
+    LayoutSyntaxTree(SynTree),
+}
+
+/// A sequence of commands, as returned by library functions to describe how
+/// their body (if any) should be laid out.
+pub type Commands = Vec<Command>;
+
+/// Mutable layouting state threaded through the layouting engine and the
+/// library functions that configure it.
+#[derive(Debug, Clone)]
+pub struct LayoutState {
+    /// The alignment currently in effect for newly laid out content.
+    pub align: LayoutAlign,
+    /// The writing system currently in effect.
+    pub sys: LayoutSystem,
+    /// The distribution mode currently in effect for runs of children laid
+    /// out along the primary axis.
+    pub distribution: Distribution,
+}
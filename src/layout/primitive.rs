@@ -0,0 +1,281 @@
+//! Layout primitives shared between the layouting engine and the standard
+//! library functions that configure it (e.g. `align`, `distribute`).
+
+use std::fmt::{self, Display, Formatter};
+
+/// One of the two physical axes of a layout.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum SpecAxis {
+    Horizontal,
+    Vertical,
+}
+
+impl SpecAxis {
+    /// Resolve this physical axis to the generic axis it maps to under the
+    /// given writing system.
+    pub fn to_gen(self, sys: LayoutSystem) -> GenAxis {
+        if self == sys.primary.axis() {
+            GenAxis::Primary
+        } else {
+            GenAxis::Secondary
+        }
+    }
+}
+
+impl Display for SpecAxis {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+        })
+    }
+}
+
+/// One of the two generic axes of a layout: the one text flows along
+/// (`Primary`) and the one lines stack along (`Secondary`).
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum GenAxis {
+    Primary,
+    Secondary,
+}
+
+/// A value present once per generic axis.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Gen2<T> {
+    pub primary: T,
+    pub secondary: T,
+}
+
+impl<T> Gen2<T> {
+    pub fn new(primary: T, secondary: T) -> Self {
+        Self { primary, secondary }
+    }
+}
+
+impl<T: Copy> Gen2<T> {
+    /// Get the component for the given generic axis.
+    pub fn get(self, axis: GenAxis) -> T {
+        match axis {
+            GenAxis::Primary => self.primary,
+            GenAxis::Secondary => self.secondary,
+        }
+    }
+
+    /// Get a mutable reference to the component for the given generic axis.
+    pub fn get_mut(&mut self, axis: GenAxis) -> &mut T {
+        match axis {
+            GenAxis::Primary => &mut self.primary,
+            GenAxis::Secondary => &mut self.secondary,
+        }
+    }
+}
+
+/// A direction an axis can run in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Dir {
+    LTR,
+    RTL,
+    TTB,
+    BTT,
+}
+
+impl Dir {
+    /// The physical axis this direction runs along.
+    pub fn axis(self) -> SpecAxis {
+        match self {
+            Self::LTR | Self::RTL => SpecAxis::Horizontal,
+            Self::TTB | Self::BTT => SpecAxis::Vertical,
+        }
+    }
+
+    /// Whether this direction runs left-to-right or top-to-bottom, as
+    /// opposed to right-to-left or bottom-to-top.
+    pub fn is_positive(self) -> bool {
+        matches!(self, Self::LTR | Self::TTB)
+    }
+}
+
+/// The writing system in effect: which direction the primary and secondary
+/// axis run in.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LayoutSystem {
+    pub primary: Dir,
+    pub secondary: Dir,
+}
+
+/// An alignment as written by the user (a physical side, `center`,
+/// `start`/`end` or a ratio), not yet resolved onto a generic axis.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SpecAlign {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    Center,
+    /// The beginning of whichever axis this ends up applying to, following
+    /// the writing direction rather than a fixed physical side.
+    Start,
+    /// The end of whichever axis this ends up applying to, following the
+    /// writing direction rather than a fixed physical side.
+    End,
+    /// A fraction of the free space along whichever axis this ends up
+    /// applying to, clamped to `[0.0, 1.0]`, where `0.0` ≡ `start`,
+    /// `0.5` ≡ `center` and `1.0` ≡ `end`.
+    Ratio(f64),
+}
+
+impl SpecAlign {
+    /// The physical axis this alignment is pinned to, if any. `center`,
+    /// `start` and `end` are axis-neutral: their axis is inferred from
+    /// context by `dedup_aligns`.
+    pub fn axis(self) -> Option<SpecAxis> {
+        match self {
+            Self::Left | Self::Right => Some(SpecAxis::Horizontal),
+            Self::Top | Self::Bottom => Some(SpecAxis::Vertical),
+            Self::Center | Self::Start | Self::End | Self::Ratio(_) => None,
+        }
+    }
+
+    /// Resolve a physical alignment to its generic counterpart under the
+    /// given writing system.
+    ///
+    /// `start`, `end` and `Ratio` already name a position on the generic
+    /// axis directly and do not need this conversion — callers go through
+    /// `dedup_aligns`'s `to_gen_align` instead, which maps them straight to
+    /// the matching `GenAlign` variant.
+    pub fn to_gen(self, sys: LayoutSystem) -> GenAlign {
+        match self {
+            Self::Left => side(sys, SpecAxis::Horizontal, false),
+            Self::Right => side(sys, SpecAxis::Horizontal, true),
+            Self::Top => side(sys, SpecAxis::Vertical, false),
+            Self::Bottom => side(sys, SpecAxis::Vertical, true),
+            Self::Center => GenAlign::Center,
+            Self::Start => GenAlign::Start,
+            Self::End => GenAlign::End,
+            Self::Ratio(r) => GenAlign::Ratio(r.max(0.0).min(1.0)),
+        }
+    }
+}
+
+/// Map a physical side of `axis` (`at_end = false` is left/top, `true` is
+/// right/bottom) to `Start`/`End` depending on whether that axis's writing
+/// direction runs positively (left-to-right/top-to-bottom).
+fn side(sys: LayoutSystem, axis: SpecAxis, at_end: bool) -> GenAlign {
+    let dir = if axis == sys.primary.axis() { sys.primary } else { sys.secondary };
+    if at_end == dir.is_positive() {
+        GenAlign::End
+    } else {
+        GenAlign::Start
+    }
+}
+
+impl Display for SpecAlign {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::Left => f.write_str("left"),
+            Self::Right => f.write_str("right"),
+            Self::Top => f.write_str("top"),
+            Self::Bottom => f.write_str("bottom"),
+            Self::Center => f.write_str("center"),
+            Self::Start => f.write_str("start"),
+            Self::End => f.write_str("end"),
+            Self::Ratio(r) => write!(f, "{}%", r * 100.0),
+        }
+    }
+}
+
+/// An alignment already resolved onto a generic axis: a position relative
+/// to the flow (`Start`/`End`) rather than to the page.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum GenAlign {
+    Start,
+    Center,
+    End,
+    /// A fraction of the free space along the axis, clamped to `[0.0, 1.0]`,
+    /// where `0.0` ≡ `Start`, `0.5` ≡ `Center` and `1.0` ≡ `End`.
+    Ratio(f64),
+}
+
+impl GenAlign {
+    /// Resolve this alignment to an offset from the start of the axis,
+    /// given the total `available` space and the `used` space occupied by
+    /// the content being positioned. This is the single site the
+    /// layouting pass calls to turn any `GenAlign` — including `Ratio` —
+    /// into an actual offset.
+    pub fn resolve(self, available: f64, used: f64) -> f64 {
+        let free = (available - used).max(0.0);
+        match self {
+            Self::Start => 0.0,
+            Self::Center => free / 2.0,
+            Self::End => free,
+            Self::Ratio(r) => r.max(0.0).min(1.0) * free,
+        }
+    }
+}
+
+/// An alignment resolved onto a generic axis, as `Gen2` stores one per
+/// axis.
+pub type LayoutAlign = Gen2<GenAlign>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ltr() -> LayoutSystem {
+        LayoutSystem { primary: Dir::LTR, secondary: Dir::TTB }
+    }
+
+    fn rtl() -> LayoutSystem {
+        LayoutSystem { primary: Dir::RTL, secondary: Dir::TTB }
+    }
+
+    #[test]
+    fn left_right_flip_under_rtl() {
+        assert_eq!(SpecAlign::Left.to_gen(ltr()), GenAlign::Start);
+        assert_eq!(SpecAlign::Right.to_gen(ltr()), GenAlign::End);
+        assert_eq!(SpecAlign::Left.to_gen(rtl()), GenAlign::End);
+        assert_eq!(SpecAlign::Right.to_gen(rtl()), GenAlign::Start);
+    }
+
+    #[test]
+    fn top_bottom_unaffected_by_horizontal_direction() {
+        assert_eq!(SpecAlign::Top.to_gen(ltr()), GenAlign::Start);
+        assert_eq!(SpecAlign::Top.to_gen(rtl()), GenAlign::Start);
+    }
+
+    #[test]
+    fn start_end_bypass_direction_entirely() {
+        assert_eq!(SpecAlign::Start.to_gen(ltr()), GenAlign::Start);
+        assert_eq!(SpecAlign::Start.to_gen(rtl()), GenAlign::Start);
+        assert_eq!(SpecAlign::End.to_gen(ltr()), GenAlign::End);
+        assert_eq!(SpecAlign::End.to_gen(rtl()), GenAlign::End);
+    }
+
+    #[test]
+    fn ratio_bypasses_direction_and_is_clamped_by_to_gen() {
+        assert_eq!(SpecAlign::Ratio(0.25).to_gen(ltr()), GenAlign::Ratio(0.25));
+        assert_eq!(SpecAlign::Ratio(0.25).to_gen(rtl()), GenAlign::Ratio(0.25));
+        assert_eq!(SpecAlign::Ratio(1.5).to_gen(ltr()), GenAlign::Ratio(1.0));
+        assert_eq!(SpecAlign::Ratio(-0.5).to_gen(ltr()), GenAlign::Ratio(0.0));
+    }
+
+    #[test]
+    fn resolve_start_center_end_positions() {
+        assert_eq!(GenAlign::Start.resolve(100.0, 40.0), 0.0);
+        assert_eq!(GenAlign::Center.resolve(100.0, 40.0), 30.0);
+        assert_eq!(GenAlign::End.resolve(100.0, 40.0), 60.0);
+    }
+
+    #[test]
+    fn resolve_ratio_interpolates_free_space_and_clamps() {
+        assert_eq!(GenAlign::Ratio(0.25).resolve(100.0, 0.0), 25.0);
+        assert_eq!(GenAlign::Ratio(1.5).resolve(100.0, 0.0), 100.0);
+        assert_eq!(GenAlign::Ratio(-0.5).resolve(100.0, 0.0), 0.0);
+    }
+
+    #[test]
+    fn resolve_never_goes_negative_when_content_overflows() {
+        assert_eq!(GenAlign::Center.resolve(50.0, 80.0), 0.0);
+        assert_eq!(GenAlign::End.resolve(50.0, 80.0), 0.0);
+    }
+}
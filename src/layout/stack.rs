@@ -0,0 +1,19 @@
+//! Positioning a child within the space allotted to it.
+
+use super::primitive::{Gen2, LayoutAlign};
+
+/// Compute the offset, along each generic axis, at which a child occupying
+/// `used` space should be placed within an `available` area, given the
+/// `align`ment in effect for each axis.
+///
+/// This is the layouting pass's call site for [`GenAlign::resolve`][resolve]:
+/// every `GenAlign` variant, including `Ratio`, is turned into an actual
+/// offset here rather than anywhere alignment is merely specified.
+///
+/// [resolve]: super::primitive::GenAlign::resolve
+pub fn align_offset(align: LayoutAlign, available: Gen2<f64>, used: Gen2<f64>) -> Gen2<f64> {
+    Gen2::new(
+        align.primary.resolve(available.primary, used.primary),
+        align.secondary.resolve(available.secondary, used.secondary),
+    )
+}
@@ -3,15 +3,26 @@ use crate::prelude::*;
 /// `align`: Align content along the layouting axes.
 ///
 /// # Positional arguments
-/// - At most two of `left`, `right`, `top`, `bottom`, `center`.
+/// - At most two of `left`, `right`, `top`, `bottom`, `center`, `start`, `end`
+///   or a ratio like `25%` / `0.75`.
 ///
-/// When `center` is used as a positional argument, it is automatically inferred
-/// which axis it should apply to depending on further arguments, defaulting
-/// to the axis, text is set along.
+/// `start` and `end` align to the beginning and the end of the current
+/// writing direction on whichever axis they end up applying to, instead of
+/// a fixed physical side. This makes them flip automatically under, e.g., a
+/// right-to-left or vertical writing mode, whereas `left`/`right` always
+/// stay put.
+///
+/// A ratio places content at that fraction of the free space along its
+/// axis, with `0%` equivalent to `start`, `50%` to `center` and `100%` to
+/// `end`. Values outside of `0%`-`100%` are clamped.
+///
+/// When `center`, `start`, `end` or a positional ratio is used, it is
+/// automatically inferred which axis it should apply to depending on further
+/// arguments, defaulting to the axis, text is set along.
 ///
 /// # Keyword arguments
-/// - `horizontal`: Any of `left`, `right` or `center`.
-/// - `vertical`: Any of `top`, `bottom` or `center`.
+/// - `horizontal`: Any of `left`, `right`, `start`, `end`, `center` or a ratio.
+/// - `vertical`: Any of `top`, `bottom`, `start`, `end`, `center` or a ratio.
 ///
 /// There may not be two alignment specifications for the same axis.
 pub async fn align(mut args: Args, ctx: &mut LayoutContext) -> Value {
@@ -48,14 +59,14 @@ fn dedup_aligns(
 ) -> LayoutAlign {
     let mut aligns = ctx.state.align;
     let mut had = Gen2::new(false, false);
-    let mut had_center = false;
+    let mut deferred: Option<Spanned<SpecAlign>> = None;
 
-    for (axis, Spanned { v: align, span }) in iter {
+    for (axis, spanned @ Spanned { v: align, span }) in iter {
         // Check whether we know which axis this alignment belongs to.
         if let Some(axis) = axis {
             // We know the axis.
             let gen_axis = axis.to_gen(ctx.state.sys);
-            let gen_align = align.to_gen(ctx.state.sys);
+            let gen_align = to_gen_align(align, ctx.state.sys);
 
             if align.axis().map_or(false, |a| a != axis) {
                 ctx.diag(error!(
@@ -69,42 +80,133 @@ fn dedup_aligns(
                 *had.get_mut(gen_axis) = true;
             }
         } else {
-            // We don't know the axis: This has to be a `center` alignment for a
-            // positional argument.
-            debug_assert_eq!(align, SpecAlign::Center);
+            // We don't know the axis: This has to be a `center`, `start`,
+            // `end` or ratio alignment given as a positional argument.
+            debug_assert!(matches!(
+                align,
+                SpecAlign::Center
+                    | SpecAlign::Start
+                    | SpecAlign::End
+                    | SpecAlign::Ratio(_)
+            ));
 
             if had.primary && had.secondary {
                 ctx.diag(error!(span, "duplicate alignment"));
-            } else if had_center {
-                // Both this and the previous one are unspecified `center`
-                // alignments. Both axes should be centered.
-                aligns = LayoutAlign::new(GenAlign::Center, GenAlign::Center);
+            } else if let Some(prev) = deferred.take() {
+                // Both this and the previous argument are axis-neutral: the
+                // first one binds to the primary axis, this one to the
+                // secondary axis.
+                let (primary, secondary) = pair_axisless(ctx.state.sys, prev.v, align);
+                aligns.primary = primary;
+                aligns.secondary = secondary;
                 had.primary = true;
                 had.secondary = true;
             } else {
-                had_center = true;
+                deferred = Some(spanned);
             }
         }
 
-        // If we we know one alignment, we can handle the unspecified `center`
-        // alignment.
-        if had_center && (had.primary || had.secondary) {
-            if had.primary {
-                aligns.secondary = GenAlign::Center;
-                had.secondary = true;
-            } else {
-                aligns.primary = GenAlign::Center;
-                had.primary = true;
+        // If we now know one axis, we can resolve a still-deferred
+        // axis-neutral alignment onto the other one.
+        if let Some(Spanned { v: align, .. }) = deferred {
+            if had.primary || had.secondary {
+                let gen_align = to_gen_align(align, ctx.state.sys);
+                if had.primary {
+                    aligns.secondary = gen_align;
+                    had.secondary = true;
+                } else {
+                    aligns.primary = gen_align;
+                    had.primary = true;
+                }
+                deferred = None;
             }
-            had_center = false;
         }
     }
 
-    // If center has not been flushed by now, it is the only argument and then
-    // we default to applying it to the primary axis.
-    if had_center {
-        aligns.primary = GenAlign::Center;
+    // If the deferred alignment has not been flushed by now, it is the only
+    // argument and then we default to applying it to the primary axis.
+    if let Some(Spanned { v: align, .. }) = deferred {
+        aligns.primary = to_gen_align(align, ctx.state.sys);
     }
 
     aligns
 }
+
+/// Convert a `SpecAlign` into the `GenAlign` it stands for.
+///
+/// Unlike `SpecAlign::to_gen`, `start`, `end` and ratios are never routed
+/// through the physical-to-generic conversion: they already name a position
+/// on the generic axis directly (a fraction of the free space for ratios, as
+/// `0.0` ≡ start, `0.5` ≡ center and `1.0` ≡ end), so they apply identically
+/// no matter the writing direction.
+fn to_gen_align(align: SpecAlign, sys: LayoutSystem) -> GenAlign {
+    match align {
+        SpecAlign::Start => GenAlign::Start,
+        SpecAlign::End => GenAlign::End,
+        SpecAlign::Ratio(ratio) => GenAlign::Ratio(ratio.max(0.0).min(1.0)),
+        align => align.to_gen(sys),
+    }
+}
+
+/// Bind two axis-neutral alignments (e.g. two positional `start`/`end`
+/// arguments) onto the primary and secondary axis respectively: the first
+/// one given to the primary axis, the second to the secondary axis. This is
+/// the pure core of `dedup_aligns`'s two-deferred-argument branch.
+fn pair_axisless(sys: LayoutSystem, first: SpecAlign, second: SpecAlign) -> (GenAlign, GenAlign) {
+    (to_gen_align(first, sys), to_gen_align(second, sys))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ltr() -> LayoutSystem {
+        LayoutSystem { primary: Dir::LTR, secondary: Dir::TTB }
+    }
+
+    fn rtl() -> LayoutSystem {
+        LayoutSystem { primary: Dir::RTL, secondary: Dir::TTB }
+    }
+
+    #[test]
+    fn pair_axisless_binds_first_to_primary_second_to_secondary() {
+        let (primary, secondary) = pair_axisless(ltr(), SpecAlign::Start, SpecAlign::End);
+        assert_eq!(primary, GenAlign::Start);
+        assert_eq!(secondary, GenAlign::End);
+    }
+
+    #[test]
+    fn pair_axisless_is_direction_independent_for_start_end() {
+        // `start`/`end` bypass the physical-to-generic conversion, so they
+        // pair the same way under LTR and RTL.
+        let (primary, secondary) = pair_axisless(rtl(), SpecAlign::Start, SpecAlign::End);
+        assert_eq!(primary, GenAlign::Start);
+        assert_eq!(secondary, GenAlign::End);
+    }
+
+    #[test]
+    fn to_gen_align_leaves_start_end_unresolved_by_direction() {
+        assert_eq!(to_gen_align(SpecAlign::Start, ltr()), GenAlign::Start);
+        assert_eq!(to_gen_align(SpecAlign::Start, rtl()), GenAlign::Start);
+        assert_eq!(to_gen_align(SpecAlign::End, ltr()), GenAlign::End);
+        assert_eq!(to_gen_align(SpecAlign::End, rtl()), GenAlign::End);
+    }
+
+    #[test]
+    fn to_gen_align_clamps_ratio_to_unit_range_regardless_of_direction() {
+        // Models `align(horizontal: 25%)` and out-of-range ratios.
+        assert_eq!(to_gen_align(SpecAlign::Ratio(1.5), ltr()), GenAlign::Ratio(1.0));
+        assert_eq!(to_gen_align(SpecAlign::Ratio(-0.5), rtl()), GenAlign::Ratio(0.0));
+        assert_eq!(to_gen_align(SpecAlign::Ratio(0.25), ltr()), GenAlign::Ratio(0.25));
+    }
+
+    #[test]
+    fn pair_axisless_handles_center_and_ratio() {
+        // Models `align(center, 75%)`: a ratio isn't pinned to either axis
+        // and pairs with whatever axis it ends up next to, just like
+        // `start`/`end`.
+        let (primary, secondary) = pair_axisless(ltr(), SpecAlign::Center, SpecAlign::Ratio(0.75));
+        assert_eq!(primary, GenAlign::Center);
+        assert_eq!(secondary, GenAlign::Ratio(0.75));
+    }
+}
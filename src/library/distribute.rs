@@ -0,0 +1,28 @@
+use crate::prelude::*;
+
+/// `distribute`: Control how a run of children is spaced along the primary
+/// axis.
+///
+/// # Positional arguments
+/// - The distribution mode: one of `start`, `end`, `center`, `between`,
+///   `around` or `evenly`.
+///
+/// Unlike `align`, which positions a single block, `distribute` decides how
+/// the free space left over after laying out a run of children is turned
+/// into gaps between them.
+pub async fn distribute(mut args: Args, ctx: &mut LayoutContext) -> Value {
+    let body = args.find::<SynTree>();
+    let dist = args.get::<_, Spanned<Distribution>>(ctx, 0);
+    args.done(ctx);
+
+    let distribution = dist.map(|d| d.v).unwrap_or(ctx.state.distribution);
+
+    Value::Commands(match body {
+        Some(tree) => vec![
+            SetDistribution(distribution),
+            LayoutSyntaxTree(tree),
+            SetDistribution(ctx.state.distribution),
+        ],
+        None => vec![SetDistribution(distribution)],
+    })
+}